@@ -0,0 +1,265 @@
+//! Bipartite assignment of unlocked issues to contributors.
+//!
+//! Builds on [`crate::algorithms::topk_set`]'s output to turn "what to
+//! unblock" into "who should take it": a maximum-weight bipartite matching
+//! between open issues and contributors, respecting per-contributor
+//! capacity, in the spirit of Garage's partition-assignment optimizer.
+//! Among equally optimal matchings, one that stays closest to a prior
+//! assignment is preferred, to avoid needless reassignment churn.
+
+use crate::algorithms::topk_set::TopKSetItem;
+use std::collections::{HashMap, VecDeque};
+
+/// A contributor available to take on issues.
+#[derive(Debug, Clone)]
+pub struct Contributor {
+    /// Opaque contributor identifier, echoed back in [`Assignment`].
+    pub id: usize,
+    /// Maximum number of issues this contributor can take on at once.
+    pub capacity: usize,
+    /// Per-issue affinity weight, keyed by issue node index. An issue with
+    /// no entry is one this contributor is not eligible for.
+    pub affinity: HashMap<usize, i64>,
+}
+
+/// A bipartite matching of issue nodes to contributor ids.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Assignment {
+    /// `(issue node, contributor id)` pairs. An issue absent from this list
+    /// was left unassigned (no eligible contributor improved on leaving it
+    /// open).
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl Assignment {
+    /// The contributor currently assigned to `issue`, if any.
+    pub fn contributor_for(&self, issue: usize) -> Option<usize> {
+        self.edges
+            .iter()
+            .find(|&&(i, _)| i == issue)
+            .map(|&(_, c)| c)
+    }
+}
+
+/// Bonus applied to an issue-contributor pair that matches `prior`, so the
+/// solver only churns an existing assignment when a strictly better one
+/// exists.
+const STABILITY_BONUS: i64 = 1;
+
+/// Assigns `items` to `contributors` to maximize total weighted capacity
+/// (`marginal_gain * affinity`, plus a small [`STABILITY_BONUS`] for pairs
+/// already present in `prior`), respecting each contributor's capacity.
+///
+/// Modeled as min-cost flow: source -> issue (capacity 1) -> eligible
+/// contributor (capacity 1, cost = negative weight) -> sink (capacity =
+/// contributor capacity). Solved via successive shortest augmenting paths
+/// with Bellman-Ford (SPFA), since edge costs can be negative; augmentation
+/// stops as soon as no path would improve total weight, so issues with no
+/// positive-weight contributor are simply left unassigned rather than
+/// forced into a matching.
+pub fn assign(
+    items: &[TopKSetItem],
+    contributors: &[Contributor],
+    prior: Option<&Assignment>,
+) -> Assignment {
+    let n_issues = items.len();
+    let n_contrib = contributors.len();
+    let source = 0;
+    let issue_node = |i: usize| 1 + i;
+    let contrib_node = |c: usize| 1 + n_issues + c;
+    let sink = contrib_node(n_contrib);
+    let n = sink + 1;
+
+    struct FlowEdge {
+        to: usize,
+        cap: i64,
+        cost: i64,
+    }
+
+    let mut edges: Vec<FlowEdge> = Vec::new();
+    let mut adj: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let add_edge = |edges: &mut Vec<FlowEdge>, adj: &mut [Vec<usize>], from: usize, to: usize, cap: i64, cost: i64| {
+        adj[from].push(edges.len());
+        edges.push(FlowEdge { to, cap, cost });
+        adj[to].push(edges.len());
+        edges.push(FlowEdge { to: from, cap: 0, cost: -cost });
+    };
+
+    for i in 0..n_issues {
+        add_edge(&mut edges, &mut adj, source, issue_node(i), 1, 0);
+    }
+    for (c_idx, contributor) in contributors.iter().enumerate() {
+        add_edge(&mut edges, &mut adj, contrib_node(c_idx), sink, contributor.capacity as i64, 0);
+    }
+    // Scale real weights past the largest possible total bonus (one per
+    // issue), so the bonus only ever breaks ties between matchings of equal
+    // real weight.
+    let scale = n_issues as i64 + 1;
+    for (i, item) in items.iter().enumerate() {
+        for (c_idx, contributor) in contributors.iter().enumerate() {
+            let Some(&affinity) = contributor.affinity.get(&item.node) else {
+                continue;
+            };
+            let mut weight = item.marginal_gain as i64 * affinity * scale;
+            if let Some(p) = prior {
+                if p.contributor_for(item.node) == Some(contributor.id) {
+                    weight += STABILITY_BONUS;
+                }
+            }
+            add_edge(&mut edges, &mut adj, issue_node(i), contrib_node(c_idx), 1, -weight);
+        }
+    }
+
+    loop {
+        let mut dist = vec![i64::MAX; n];
+        let mut prev_edge: Vec<Option<usize>> = vec![None; n];
+        let mut in_queue = vec![false; n];
+        let mut queue = VecDeque::new();
+        dist[source] = 0;
+        queue.push_back(source);
+        in_queue[source] = true;
+        while let Some(u) = queue.pop_front() {
+            in_queue[u] = false;
+            for &e_idx in &adj[u] {
+                let e = &edges[e_idx];
+                if e.cap > 0 && dist[u] != i64::MAX && dist[u] + e.cost < dist[e.to] {
+                    dist[e.to] = dist[u] + e.cost;
+                    prev_edge[e.to] = Some(e_idx);
+                    if !in_queue[e.to] {
+                        queue.push_back(e.to);
+                        in_queue[e.to] = true;
+                    }
+                }
+            }
+        }
+
+        // A negative-cost path to the sink means routing one more unit of
+        // flow increases total weight; stop once no such path remains.
+        if dist[sink] == i64::MAX || dist[sink] >= 0 {
+            break;
+        }
+
+        let mut path = Vec::new();
+        let mut v = sink;
+        while let Some(e_idx) = prev_edge[v] {
+            path.push(e_idx);
+            v = edges[e_idx ^ 1].to;
+        }
+        for e_idx in path {
+            edges[e_idx].cap -= 1;
+            edges[e_idx ^ 1].cap += 1;
+        }
+    }
+
+    let mut result_edges = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        for &e_idx in &adj[issue_node(i)] {
+            let e = &edges[e_idx];
+            // A saturated forward edge into a contributor node carries flow.
+            if e.cap == 0 && e.to != source && e.to < sink {
+                let c_idx = e.to - contrib_node(0);
+                result_edges.push((item.node, contributors[c_idx].id));
+            }
+        }
+    }
+
+    Assignment {
+        edges: result_edges,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(node: usize, gain: usize) -> TopKSetItem {
+        TopKSetItem {
+            node,
+            marginal_gain: gain,
+            unblocked_ids: Vec::new(),
+            lockout_bonus: 0,
+        }
+    }
+
+    fn contributor(id: usize, capacity: usize, affinity: &[(usize, i64)]) -> Contributor {
+        Contributor {
+            id,
+            capacity,
+            affinity: affinity.iter().copied().collect(),
+        }
+    }
+
+    #[test]
+    fn test_no_contributors_leaves_everything_unassigned() {
+        let items = vec![item(0, 5)];
+        let result = assign(&items, &[], None);
+        assert!(result.edges.is_empty());
+    }
+
+    #[test]
+    fn test_single_issue_prefers_higher_affinity() {
+        let items = vec![item(0, 2)];
+        let contributors = vec![
+            contributor(1, 1, &[(0, 1)]),
+            contributor(2, 1, &[(0, 5)]),
+        ];
+        let result = assign(&items, &contributors, None);
+        assert_eq!(result.edges, vec![(0, 2)]);
+    }
+
+    #[test]
+    fn test_ineligible_contributor_is_skipped() {
+        let items = vec![item(0, 2)];
+        let contributors = vec![contributor(1, 1, &[(1, 5)])];
+        let result = assign(&items, &contributors, None);
+        assert!(result.edges.is_empty());
+    }
+
+    #[test]
+    fn test_capacity_limits_assignments() {
+        // Two issues, one contributor with capacity 1: only the
+        // higher-weight issue should be picked up.
+        let items = vec![item(0, 1), item(1, 5)];
+        let contributors = vec![contributor(1, 1, &[(0, 1), (1, 1)])];
+        let result = assign(&items, &contributors, None);
+        assert_eq!(result.edges, vec![(1, 1)]);
+    }
+
+    #[test]
+    fn test_capacity_respects_multiple_slots() {
+        let items = vec![item(0, 1), item(1, 1)];
+        let contributors = vec![contributor(1, 2, &[(0, 1), (1, 1)])];
+        let result = assign(&items, &contributors, None);
+        let mut edges = result.edges.clone();
+        edges.sort_unstable();
+        assert_eq!(edges, vec![(0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn test_prior_assignment_sticks_on_a_tie() {
+        let items = vec![item(0, 1)];
+        let contributors = vec![
+            contributor(1, 1, &[(0, 3)]),
+            contributor(2, 1, &[(0, 3)]),
+        ];
+        let prior = Assignment {
+            edges: vec![(0, 2)],
+        };
+        let result = assign(&items, &contributors, Some(&prior));
+        assert_eq!(result.edges, vec![(0, 2)]);
+    }
+
+    #[test]
+    fn test_strictly_better_contributor_overrides_prior() {
+        let items = vec![item(0, 1)];
+        let contributors = vec![
+            contributor(1, 1, &[(0, 1)]),
+            contributor(2, 1, &[(0, 10)]),
+        ];
+        let prior = Assignment {
+            edges: vec![(0, 1)],
+        };
+        let result = assign(&items, &contributors, Some(&prior));
+        assert_eq!(result.edges, vec![(0, 2)]);
+    }
+}
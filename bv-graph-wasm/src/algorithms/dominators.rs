@@ -0,0 +1,238 @@
+//! Dominator tree analysis: "which upstream issue, if unresolved, blocks
+//! this entire subtree".
+//!
+//! Follows the petgraph/rustc approach to computing dominators: the
+//! iterative data-flow formulation of Cooper, Harvey & Kennedy, which
+//! processes nodes in reverse postorder, repeatedly intersecting the
+//! dominator sets of predecessors via a "two-finger" walk up the dominator
+//! tree keyed by postorder number, until it reaches a fixpoint.
+//!
+//! A node `d` dominates `n` if every path from the root to `n` passes
+//! through `d`. This is strictly stronger than the articulation-point
+//! analysis in [`crate::algorithms::articulation`] because it is directional
+//! and relative to a chosen root.
+
+use crate::graph::DiGraph;
+use std::collections::HashMap;
+
+/// The dominator tree of a graph relative to a single root.
+///
+/// Only nodes reachable from `root` are assigned a dominator; queries about
+/// unreachable nodes return empty results.
+#[derive(Debug, Clone)]
+pub struct Dominators {
+    root: usize,
+    idom: HashMap<usize, usize>,
+}
+
+impl Dominators {
+    /// The root this dominator tree was computed from.
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    /// The immediate dominator of `node`, or `None` for the root itself or
+    /// for a node unreachable from the root.
+    pub fn immediate_dominator(&self, node: usize) -> Option<usize> {
+        if node == self.root {
+            return None;
+        }
+        self.idom.get(&node).copied()
+    }
+
+    /// All dominators of `node`, ordered from the root down to `node`
+    /// (inclusive of both endpoints). Empty if `node` is unreachable from
+    /// the root.
+    pub fn dominators(&self, node: usize) -> Vec<usize> {
+        if node != self.root && !self.idom.contains_key(&node) {
+            return Vec::new();
+        }
+        let mut chain = vec![node];
+        let mut current = node;
+        while current != self.root {
+            current = self.idom[&current];
+            chain.push(current);
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Every node strictly dominated by `node`, i.e. its subtree in the
+    /// dominator tree: the issues that become permanently un-actionable if
+    /// `node` is never resolved.
+    pub fn dominated_by(&self, node: usize) -> Vec<usize> {
+        let mut result: Vec<usize> = self
+            .idom
+            .keys()
+            .copied()
+            .filter(|&n| n != node && self.dominators(n).contains(&node))
+            .collect();
+        result.sort_unstable();
+        result
+    }
+}
+
+/// Computes the dominator tree of `graph` rooted at `root`.
+pub fn compute_dominators(graph: &DiGraph, root: usize) -> Dominators {
+    let postorder = postorder_from(graph, root);
+    let mut postorder_num: Vec<Option<usize>> = vec![None; graph.len()];
+    for (i, &node) in postorder.iter().enumerate() {
+        postorder_num[node] = Some(i);
+    }
+    let reverse_postorder: Vec<usize> = postorder.iter().rev().copied().collect();
+
+    let mut idom: HashMap<usize, usize> = HashMap::new();
+    idom.insert(root, root);
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &node in &reverse_postorder {
+            if node == root {
+                continue;
+            }
+            let mut new_idom: Option<usize> = None;
+            for pred in graph.predecessors(node) {
+                if !idom.contains_key(&pred) {
+                    continue;
+                }
+                new_idom = Some(match new_idom {
+                    None => pred,
+                    Some(current) => intersect(current, pred, &idom, &postorder_num),
+                });
+            }
+            if let Some(new_idom) = new_idom {
+                if idom.get(&node) != Some(&new_idom) {
+                    idom.insert(node, new_idom);
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    Dominators { root, idom }
+}
+
+/// Computes one dominator tree per source node (a node with no incoming
+/// edges), for graphs with no single natural root.
+pub fn dominator_forest(graph: &DiGraph) -> Vec<Dominators> {
+    (0..graph.len())
+        .filter(|&node| graph.predecessors(node).next().is_none())
+        .map(|root| compute_dominators(graph, root))
+        .collect()
+}
+
+fn intersect(
+    mut a: usize,
+    mut b: usize,
+    idom: &HashMap<usize, usize>,
+    postorder_num: &[Option<usize>],
+) -> usize {
+    while a != b {
+        while postorder_num[a] < postorder_num[b] {
+            a = idom[&a];
+        }
+        while postorder_num[b] < postorder_num[a] {
+            b = idom[&b];
+        }
+    }
+    a
+}
+
+fn postorder_from(graph: &DiGraph, root: usize) -> Vec<usize> {
+    let n = graph.len();
+    let mut visited = vec![false; n];
+    let mut order = Vec::new();
+    let mut stack = vec![(root, false)];
+    visited[root] = true;
+    while let Some((node, expanded)) = stack.pop() {
+        if expanded {
+            order.push(node);
+            continue;
+        }
+        stack.push((node, true));
+        for succ in graph.successors(node) {
+            if !visited[succ] {
+                visited[succ] = true;
+                stack.push((succ, false));
+            }
+        }
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_graph(edges: &[(usize, usize)]) -> DiGraph {
+        let mut g = DiGraph::new();
+        let max_node = edges.iter().flat_map(|(a, b)| [*a, *b]).max().unwrap_or(0);
+        for i in 0..=max_node {
+            g.add_node(&format!("n{}", i));
+        }
+        for (from, to) in edges {
+            g.add_edge(*from, *to);
+        }
+        g
+    }
+
+    #[test]
+    fn test_chain() {
+        let g = make_graph(&[(0, 1), (1, 2), (2, 3)]);
+        let doms = compute_dominators(&g, 0);
+        assert_eq!(doms.immediate_dominator(1), Some(0));
+        assert_eq!(doms.immediate_dominator(2), Some(1));
+        assert_eq!(doms.immediate_dominator(3), Some(2));
+        assert_eq!(doms.dominators(3), vec![0, 1, 2, 3]);
+        assert_eq!(doms.immediate_dominator(0), None);
+    }
+
+    #[test]
+    fn test_diamond() {
+        // 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3
+        let g = make_graph(&[(0, 1), (0, 2), (1, 3), (2, 3)]);
+        let doms = compute_dominators(&g, 0);
+        // 3 is reachable via two disjoint paths, so only the root dominates it.
+        assert_eq!(doms.immediate_dominator(3), Some(0));
+        assert_eq!(doms.dominators(3), vec![0, 3]);
+    }
+
+    #[test]
+    fn test_dominated_by_subtree() {
+        let g = make_graph(&[(0, 1), (1, 2), (1, 3)]);
+        let doms = compute_dominators(&g, 0);
+        let mut subtree = doms.dominated_by(1);
+        subtree.sort_unstable();
+        assert_eq!(subtree, vec![2, 3]);
+        assert_eq!(doms.dominated_by(0), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_unreachable_node() {
+        let g = make_graph(&[(0, 1), (2, 3)]);
+        let doms = compute_dominators(&g, 0);
+        assert_eq!(doms.immediate_dominator(3), None);
+        assert!(doms.dominators(3).is_empty());
+    }
+
+    #[test]
+    fn test_dominator_forest_multiple_sources() {
+        let g = make_graph(&[(0, 1), (2, 3)]);
+        let forest = dominator_forest(&g);
+        assert_eq!(forest.len(), 2);
+        let roots: Vec<usize> = forest.iter().map(Dominators::root).collect();
+        assert!(roots.contains(&0));
+        assert!(roots.contains(&2));
+    }
+
+    #[test]
+    fn test_single_node() {
+        let mut g = DiGraph::new();
+        g.add_node("a");
+        let doms = compute_dominators(&g, 0);
+        assert_eq!(doms.immediate_dominator(0), None);
+        assert_eq!(doms.dominators(0), vec![0]);
+        assert!(doms.dominated_by(0).is_empty());
+    }
+}
@@ -3,17 +3,22 @@
 //! This module contains ports of the Go graph algorithms to Rust WASM.
 
 pub mod articulation;
+pub mod assignment;
 pub mod betweenness;
 pub mod coverage;
 pub mod critical_path;
 pub mod cycles;
+pub mod dominators;
 pub mod eigenvector;
+pub mod feedback_arc_set;
 pub mod hits;
 pub mod k_paths;
 pub mod kcore;
 pub mod pagerank;
 pub mod parallel_cut;
+pub mod scc;
 pub mod slack;
 pub mod subgraph;
 pub mod topo;
 pub mod topk_set;
+pub mod tred;
@@ -0,0 +1,198 @@
+//! Strongly-connected-component condensation into a DAG supergraph.
+//!
+//! Lets `topk_set`, `slack`, and `critical_path` operate on tangled issue
+//! graphs where mutual-blocking clusters currently break the acyclic
+//! assumptions those algorithms make, and lets the viewer render a
+//! collapsed high-level overview.
+
+use crate::graph::DiGraph;
+use std::collections::HashSet;
+
+/// Computes the strongly connected components of `graph` using Tarjan's
+/// linear-time algorithm: a single DFS maintaining an explicit stack and
+/// `lowlink`/`index` arrays, popping a component whenever `lowlink[v] ==
+/// index[v]`.
+///
+/// Run iteratively (rather than via recursion) so deep issue chains don't
+/// overflow the call stack. Components are returned in no particular order;
+/// a singleton component just means that node has no cyclic dependency on
+/// itself.
+pub fn scc(graph: &DiGraph) -> Vec<Vec<usize>> {
+    let n = graph.len();
+    let mut index: Vec<Option<usize>> = vec![None; n];
+    let mut lowlink = vec![0usize; n];
+    let mut on_stack = vec![false; n];
+    let mut tarjan_stack: Vec<usize> = Vec::new();
+    let mut components = Vec::new();
+    let mut next_index = 0;
+
+    for start in 0..n {
+        if index[start].is_some() {
+            continue;
+        }
+
+        let mut call_stack: Vec<(usize, std::vec::IntoIter<usize>)> = Vec::new();
+        index[start] = Some(next_index);
+        lowlink[start] = next_index;
+        next_index += 1;
+        tarjan_stack.push(start);
+        on_stack[start] = true;
+        call_stack.push((start, graph.successors(start).collect::<Vec<_>>().into_iter()));
+
+        while !call_stack.is_empty() {
+            let frame = call_stack.len() - 1;
+            let node = call_stack[frame].0;
+            let next_succ = call_stack[frame].1.next();
+
+            match next_succ {
+                Some(succ) => {
+                    if index[succ].is_none() {
+                        index[succ] = Some(next_index);
+                        lowlink[succ] = next_index;
+                        next_index += 1;
+                        tarjan_stack.push(succ);
+                        on_stack[succ] = true;
+                        call_stack.push((succ, graph.successors(succ).collect::<Vec<_>>().into_iter()));
+                    } else if on_stack[succ] {
+                        lowlink[node] = lowlink[node].min(index[succ].unwrap());
+                    }
+                }
+                None => {
+                    call_stack.pop();
+                    if let Some(&(parent, _)) = call_stack.last() {
+                        lowlink[parent] = lowlink[parent].min(lowlink[node]);
+                    }
+                    if lowlink[node] == index[node].unwrap() {
+                        let mut component = Vec::new();
+                        loop {
+                            let w = tarjan_stack.pop().unwrap();
+                            on_stack[w] = false;
+                            component.push(w);
+                            if w == node {
+                                break;
+                            }
+                        }
+                        components.push(component);
+                    }
+                }
+            }
+        }
+    }
+
+    components
+}
+
+/// Condenses `graph` into a DAG of strongly connected components.
+///
+/// Each SCC becomes one super-node (labelled `scc0`, `scc1`, ...) and
+/// duplicate edges between the same pair of super-nodes are collapsed into
+/// one. Returns the condensed graph plus a mapping from each original node
+/// index to its super-node index.
+pub fn condense(graph: &DiGraph) -> (DiGraph, Vec<usize>) {
+    let components = scc(graph);
+    let n = graph.len();
+    let mut node_to_component = vec![0usize; n];
+    for (comp_idx, component) in components.iter().enumerate() {
+        for &node in component {
+            node_to_component[node] = comp_idx;
+        }
+    }
+
+    let mut condensed = DiGraph::new();
+    for comp_idx in 0..components.len() {
+        condensed.add_node(&format!("scc{}", comp_idx));
+    }
+
+    let mut seen_edges = HashSet::new();
+    for (u, v) in graph.edges() {
+        let cu = node_to_component[u];
+        let cv = node_to_component[v];
+        if cu != cv && seen_edges.insert((cu, cv)) {
+            condensed.add_edge(cu, cv);
+        }
+    }
+
+    (condensed, node_to_component)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_graph(edges: &[(usize, usize)]) -> DiGraph {
+        let mut g = DiGraph::new();
+        let max_node = edges.iter().flat_map(|(a, b)| [*a, *b]).max().unwrap_or(0);
+        for i in 0..=max_node {
+            g.add_node(&format!("n{}", i));
+        }
+        for (from, to) in edges {
+            g.add_edge(*from, *to);
+        }
+        g
+    }
+
+    fn sorted_components(components: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+        let mut components: Vec<Vec<usize>> = components
+            .into_iter()
+            .map(|mut c| {
+                c.sort_unstable();
+                c
+            })
+            .collect();
+        components.sort();
+        components
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let g = DiGraph::new();
+        assert!(scc(&g).is_empty());
+    }
+
+    #[test]
+    fn test_acyclic_graph_is_all_singletons() {
+        let g = make_graph(&[(0, 1), (1, 2), (2, 3)]);
+        let components = sorted_components(scc(&g));
+        assert_eq!(components, vec![vec![0], vec![1], vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn test_single_cycle() {
+        let g = make_graph(&[(0, 1), (1, 2), (2, 0)]);
+        let components = sorted_components(scc(&g));
+        assert_eq!(components, vec![vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn test_mixed_cyclic_and_acyclic() {
+        // 0 <-> 1 (cycle), 1 -> 2 (acyclic tail)
+        let g = make_graph(&[(0, 1), (1, 0), (1, 2)]);
+        let components = sorted_components(scc(&g));
+        assert_eq!(components, vec![vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    fn test_condense_collapses_cycle_and_dedups_edges() {
+        // Two 2-cycles {0,1} and {2,3}, with two parallel 0->2 / 1->2 edges
+        // both crossing the boundary; they should collapse into one.
+        let g = make_graph(&[(0, 1), (1, 0), (2, 3), (3, 2), (0, 2), (1, 2)]);
+        let (condensed, mapping) = condense(&g);
+        assert_eq!(condensed.len(), 2);
+        assert_eq!(mapping[0], mapping[1]);
+        assert_eq!(mapping[2], mapping[3]);
+        assert_ne!(mapping[0], mapping[2]);
+        assert_eq!(condensed.edges().count(), 1);
+    }
+
+    #[test]
+    fn test_condense_of_acyclic_graph_is_isomorphic() {
+        let g = make_graph(&[(0, 1), (1, 2)]);
+        let (condensed, mapping) = condense(&g);
+        assert_eq!(condensed.len(), 3);
+        let mut distinct = mapping.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        assert_eq!(distinct.len(), 3);
+        assert_eq!(condensed.edges().count(), 2);
+    }
+}
@@ -7,6 +7,8 @@
 use crate::graph::DiGraph;
 use crate::whatif::what_if_close;
 use serde::Serialize;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
 
 /// An item in the TopK Set result.
 #[derive(Debug, Clone, Serialize)]
@@ -17,6 +19,10 @@ pub struct TopKSetItem {
     pub marginal_gain: usize,
     /// IDs of nodes that become actionable after this selection
     pub unblocked_ids: Vec<usize>,
+    /// Extra score from [`topk_set_with_lockout`]'s aging bonus, on top of
+    /// `marginal_gain`, so the UI can explain why a low-impact item was
+    /// surfaced. Always `0` for `topk_set` and `topk_set_lazy`.
+    pub lockout_bonus: usize,
 }
 
 /// Result of the TopK Set algorithm.
@@ -96,6 +102,7 @@ pub fn topk_set(graph: &DiGraph, closed_set: &[bool], k: usize) -> TopKSetResult
                     node,
                     marginal_gain: best_gain,
                     unblocked_ids: best_unblocked.clone(),
+                    lockout_bonus: 0,
                 });
                 // Mark the selected node as closed
                 current_closed[node] = true;
@@ -123,6 +130,237 @@ pub fn topk_set_default(graph: &DiGraph, closed_set: &[bool]) -> TopKSetResult {
     topk_set(graph, closed_set, 5)
 }
 
+/// Greedy selection for maximum unlock with exponential lockout weighting.
+///
+/// Inspired by Solana's time-lock fork selection: issues repeatedly passed
+/// over accrue increasing priority, preventing starvation of low-unlock,
+/// long-stalled work. Each open node's effective score is
+/// `marginal_gain + floor(lockout_weight * 2^min(age, lockout_cap))`, so a
+/// deferred node's priority bonus doubles each aging period until it hits
+/// the ceiling at `lockout_cap`. The node with the maximum effective score
+/// is selected each round, instead of the one with maximum raw gain.
+///
+/// # Arguments
+/// * `graph` - The directed dependency graph
+/// * `closed_set` - Boolean array where true means the node is closed/completed
+/// * `k` - Maximum number of items to select
+/// * `age` - Rounds each node has stayed open, parallel to the graph's nodes
+/// * `lockout_weight` - Bonus weight per aging period; `0` reproduces
+///   `topk_set`'s pure-gain behavior exactly, including its deterministic
+///   tie-breaking by node index
+/// * `lockout_cap` - Maximum aging exponent, so the bonus plateaus instead
+///   of growing unboundedly for very old issues
+///
+/// # Returns
+/// TopKSetResult with each item's `marginal_gain` holding the raw gain and
+/// `lockout_bonus` holding the bonus that was added to reach the effective
+/// score used for selection.
+pub fn topk_set_with_lockout(
+    graph: &DiGraph,
+    closed_set: &[bool],
+    k: usize,
+    age: &[u32],
+    lockout_weight: usize,
+    lockout_cap: u32,
+) -> TopKSetResult {
+    let n = graph.len();
+    if n == 0 || k == 0 {
+        return TopKSetResult {
+            items: Vec::new(),
+            total_gain: 0,
+            open_nodes: 0,
+        };
+    }
+
+    let mut current_closed = closed_set.to_vec();
+    current_closed.resize(n, false);
+    let mut ages = age.to_vec();
+    ages.resize(n, 0);
+    let open_nodes = (0..n).filter(|&i| !current_closed[i]).count();
+
+    let mut selected = Vec::new();
+    let mut total_gain = 0;
+
+    for _ in 0..k {
+        let mut candidates: Vec<usize> = (0..n).filter(|&i| !current_closed[i]).collect();
+        candidates.sort_unstable();
+
+        let mut best_node: Option<usize> = None;
+        let mut best_score: u64 = 0;
+        let mut best_gain: usize = 0;
+        let mut best_unblocked: Vec<usize> = Vec::new();
+
+        for node in candidates {
+            let result = what_if_close(graph, node, &current_closed);
+            let gain = result.transitive_unblocks;
+            let exponent = ages[node].min(lockout_cap).min(63);
+            let bonus = lockout_weight as u64 * (1u64 << exponent);
+            let score = gain as u64 + bonus;
+
+            // Prefer higher score, or lower node index for determinism.
+            if score > best_score || (score == best_score && best_node.is_none()) {
+                best_score = score;
+                best_gain = gain;
+                best_node = Some(node);
+                best_unblocked = result.cascade_ids;
+            }
+        }
+
+        match best_node {
+            Some(node) if best_score > 0 => {
+                let lockout_bonus = (best_score - best_gain as u64) as usize;
+                selected.push(TopKSetItem {
+                    node,
+                    marginal_gain: best_gain,
+                    unblocked_ids: best_unblocked.clone(),
+                    lockout_bonus,
+                });
+                current_closed[node] = true;
+                for &cascade_node in &best_unblocked {
+                    if cascade_node < n {
+                        current_closed[cascade_node] = true;
+                    }
+                }
+                total_gain += best_gain;
+            }
+            _ => break,
+        }
+    }
+
+    TopKSetResult {
+        items: selected,
+        total_gain,
+        open_nodes,
+    }
+}
+
+/// A lazily-evaluated candidate in [`topk_set_lazy`]'s priority queue.
+///
+/// `round` records the iteration in which `gain` was last computed; a pop
+/// whose `round` matches the current iteration has a gain that cannot have
+/// gone stale, by submodularity (see below), and can be selected outright.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct LazyCandidate {
+    gain: usize,
+    node: usize,
+    round: usize,
+    unblocked_ids: Vec<usize>,
+}
+
+impl Ord for LazyCandidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Max-heap on gain; break ties toward the lower node index so the
+        // result matches `topk_set`'s left-to-right tie-breaking exactly.
+        self.gain
+            .cmp(&other.gain)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl PartialOrd for LazyCandidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Lazy-greedy (CELF) selection for maximum unlock.
+///
+/// Produces exactly the same selections as [`topk_set`], but exploits the
+/// submodularity of marginal gain under set closure: once a node's gain has
+/// been recomputed against the *current* closed set, no other node's gain
+/// can have increased since the last time it was recomputed, so the top of
+/// a max-heap keyed on cached gain is optimal as soon as its cached round
+/// matches the current round. This avoids re-running `what_if_close` for
+/// every open node on every round, typically cutting the call count by an
+/// order of magnitude on large graphs.
+///
+/// # Arguments
+/// * `graph` - The directed dependency graph
+/// * `closed_set` - Boolean array where true means the node is closed/completed
+/// * `k` - Maximum number of items to select
+///
+/// # Returns
+/// TopKSetResult identical to `topk_set(graph, closed_set, k)`.
+pub fn topk_set_lazy(graph: &DiGraph, closed_set: &[bool], k: usize) -> TopKSetResult {
+    let n = graph.len();
+    if n == 0 || k == 0 {
+        return TopKSetResult {
+            items: Vec::new(),
+            total_gain: 0,
+            open_nodes: 0,
+        };
+    }
+
+    let mut current_closed = closed_set.to_vec();
+    current_closed.resize(n, false);
+    let open_nodes = (0..n).filter(|&i| !current_closed[i]).count();
+
+    // Seed the heap with every open node's gain under round 0.
+    let mut heap: BinaryHeap<LazyCandidate> = BinaryHeap::new();
+    for node in 0..n {
+        if !current_closed[node] {
+            let result = what_if_close(graph, node, &current_closed);
+            heap.push(LazyCandidate {
+                gain: result.transitive_unblocks,
+                node,
+                round: 0,
+                unblocked_ids: result.cascade_ids,
+            });
+        }
+    }
+
+    let mut selected = Vec::new();
+    let mut total_gain = 0;
+
+    'rounds: for round in 1..=k {
+        loop {
+            let Some(candidate) = heap.pop() else {
+                break 'rounds;
+            };
+            // Already closed via another node's cascade; discard and retry.
+            if current_closed[candidate.node] {
+                continue;
+            }
+            if candidate.round == round {
+                // Gain was computed against the current closed set: by
+                // submodularity it cannot be beaten by any stale entry
+                // still sitting in the heap, so it is safe to take now.
+                if candidate.gain == 0 {
+                    break 'rounds;
+                }
+                current_closed[candidate.node] = true;
+                for &cascade_node in &candidate.unblocked_ids {
+                    if cascade_node < n {
+                        current_closed[cascade_node] = true;
+                    }
+                }
+                total_gain += candidate.gain;
+                selected.push(TopKSetItem {
+                    node: candidate.node,
+                    marginal_gain: candidate.gain,
+                    unblocked_ids: candidate.unblocked_ids,
+                    lockout_bonus: 0,
+                });
+                break;
+            }
+            // Stale: recompute against the current closed set and push back.
+            let result = what_if_close(graph, candidate.node, &current_closed);
+            heap.push(LazyCandidate {
+                gain: result.transitive_unblocks,
+                node: candidate.node,
+                round,
+                unblocked_ids: result.cascade_ids,
+            });
+        }
+    }
+
+    TopKSetResult {
+        items: selected,
+        total_gain,
+        open_nodes,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,4 +546,102 @@ mod tests {
         assert_eq!(result.items[0].node, 0);
         assert_eq!(result.items[0].marginal_gain, 9);
     }
+
+    /// Asserts `topk_set_lazy` matches `topk_set` exactly, node-for-node and
+    /// gain-for-gain, on every fixture above.
+    fn assert_lazy_matches_greedy(g: &DiGraph, closed: &[bool], k: usize) {
+        let greedy = topk_set(g, closed, k);
+        let lazy = topk_set_lazy(g, closed, k);
+        assert_eq!(lazy.total_gain, greedy.total_gain);
+        assert_eq!(lazy.open_nodes, greedy.open_nodes);
+        assert_eq!(lazy.items.len(), greedy.items.len());
+        for (a, b) in lazy.items.iter().zip(greedy.items.iter()) {
+            assert_eq!(a.node, b.node);
+            assert_eq!(a.marginal_gain, b.marginal_gain);
+            assert_eq!(a.unblocked_ids, b.unblocked_ids);
+        }
+    }
+
+    #[test]
+    fn test_lazy_matches_greedy_on_all_fixtures() {
+        assert_lazy_matches_greedy(&DiGraph::new(), &[], 5);
+
+        let mut single = DiGraph::new();
+        single.add_node("a");
+        assert_lazy_matches_greedy(&single, &[false], 5);
+
+        assert_lazy_matches_greedy(&make_graph(&[(0, 1), (1, 2), (2, 3)]), &[false; 4], 5);
+        assert_lazy_matches_greedy(&make_graph(&[(0, 1), (0, 2), (0, 3)]), &[false; 4], 5);
+        assert_lazy_matches_greedy(
+            &make_graph(&[(0, 1), (0, 2), (3, 4), (3, 5), (3, 6)]),
+            &[false; 7],
+            5,
+        );
+        assert_lazy_matches_greedy(&make_graph(&[(0, 1), (1, 2), (2, 3), (3, 4)]), &[false; 5], 5);
+        assert_lazy_matches_greedy(
+            &make_graph(&[(0, 2), (1, 2), (2, 3)]),
+            &[true, false, false, false],
+            5,
+        );
+        assert_lazy_matches_greedy(
+            &make_graph(&[(0, 1), (2, 3), (4, 5), (6, 7), (8, 9)]),
+            &[false; 10],
+            2,
+        );
+        assert_lazy_matches_greedy(&make_graph(&[(0, 1), (2, 3)]), &[false; 4], 5);
+        assert_lazy_matches_greedy(
+            &make_graph(&[(0, 1), (0, 2), (0, 3), (1, 4), (1, 5)]),
+            &[false; 6],
+            5,
+        );
+        assert_lazy_matches_greedy(&make_graph(&[(0, 1), (1, 2)]), &[true, false, false], 5);
+        assert_lazy_matches_greedy(&make_graph(&[(0, 1), (1, 2)]), &[true, true, true], 5);
+
+        let mut chain_edges = Vec::new();
+        for i in 0..9 {
+            chain_edges.push((i, i + 1));
+        }
+        assert_lazy_matches_greedy(&make_graph(&chain_edges), &[false; 10], 5);
+    }
+
+    #[test]
+    fn test_lockout_zero_weight_matches_pure_gain() {
+        let g = make_graph(&[(0, 1), (0, 2), (3, 4), (3, 5), (3, 6)]);
+        let greedy = topk_set(&g, &[false; 7], 5);
+        let aged = topk_set_with_lockout(&g, &[false; 7], 5, &[0; 7], 0, 10);
+        assert_eq!(aged.total_gain, greedy.total_gain);
+        assert_eq!(aged.items.len(), greedy.items.len());
+        for (a, b) in aged.items.iter().zip(greedy.items.iter()) {
+            assert_eq!(a.node, b.node);
+            assert_eq!(a.marginal_gain, b.marginal_gain);
+            assert_eq!(a.lockout_bonus, 0);
+        }
+    }
+
+    #[test]
+    fn test_lockout_bonus_can_surface_a_stalled_low_gain_node() {
+        // Hub1 -> {A, B} (gain 2), Hub2 (gain 1) but aged for a long time.
+        let g = make_graph(&[(0, 1), (0, 2), (3, 4)]);
+        // At age 0 every node gets the same +1 bonus, so hub1 (node 0)
+        // still wins on its raw gain advantage.
+        let unaged = topk_set_with_lockout(&g, &[false; 5], 1, &[0; 5], 1, 10);
+        assert_eq!(unaged.items[0].node, 0);
+
+        // Node 3 has been stalled long enough that its lockout bonus
+        // outweighs hub1's raw gain advantage.
+        let mut age = vec![0u32; 5];
+        age[3] = 3; // bonus = 1 * 2^3 = 8
+        let aged = topk_set_with_lockout(&g, &[false; 5], 1, &age, 1, 10);
+        assert_eq!(aged.items[0].node, 3);
+        assert_eq!(aged.items[0].marginal_gain, 1);
+        assert_eq!(aged.items[0].lockout_bonus, 8);
+    }
+
+    #[test]
+    fn test_lockout_cap_bounds_the_bonus() {
+        let g = make_graph(&[(0, 1)]);
+        let aged = topk_set_with_lockout(&g, &[false; 2], 1, &[100, 0], 1, 4);
+        // Exponent is capped at 4, so bonus is 2^4 = 16, not 2^100.
+        assert_eq!(aged.items[0].lockout_bonus, 16);
+    }
 }
@@ -0,0 +1,144 @@
+//! Transitive reduction: the minimal edge set preserving reachability.
+//!
+//! Mirrors petgraph's `tred` module. For issue graphs this collapses
+//! redundant "A blocks C" links when "A blocks B blocks C" already implies
+//! them, decluttering the rendered dependency view.
+
+use crate::graph::DiGraph;
+use std::collections::HashSet;
+
+/// Result of [`transitive_reduction`].
+#[derive(Debug, Clone)]
+pub struct TransitiveReductionResult {
+    /// The graph with every redundant edge removed.
+    pub reduced: DiGraph,
+    /// Edges dropped because a longer path already implied them, so the
+    /// viewer can offer a toggle between the full and reduced views.
+    pub removed_edges: Vec<(usize, usize)>,
+}
+
+/// Error returned when transitive reduction is attempted on a cyclic graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TredError {
+    /// The graph contains at least one cycle; transitive reduction is only
+    /// defined (and unique) on a DAG. Condense SCCs first if needed.
+    Cyclic,
+}
+
+/// Computes the transitive reduction of `graph`.
+///
+/// Drops each edge `(u, v)` for which some other direct successor `w` of
+/// `u` (`w != v`) already reaches `v`, since `(u, v)` is then implied by the
+/// longer path `u -> w ~> v`. The result is the unique minimal edge set
+/// with the same reachability relation as `graph`.
+///
+/// # Errors
+/// Returns [`TredError::Cyclic`] if `graph` is not acyclic.
+pub fn transitive_reduction(graph: &DiGraph) -> Result<TransitiveReductionResult, TredError> {
+    let n = graph.len();
+    let reach = reachability_closure(graph);
+
+    for (node, reachable) in reach.iter().enumerate() {
+        if reachable.contains(&node) {
+            return Err(TredError::Cyclic);
+        }
+    }
+
+    let mut reduced = DiGraph::new();
+    for node in 0..n {
+        reduced.add_node(graph.node_label(node));
+    }
+
+    let mut removed_edges = Vec::new();
+    for u in 0..n {
+        let direct: Vec<usize> = graph.successors(u).collect();
+        for &v in &direct {
+            let implied = direct.iter().any(|&w| w != v && reach[w].contains(&v));
+            if implied {
+                removed_edges.push((u, v));
+            } else {
+                reduced.add_edge(u, v);
+            }
+        }
+    }
+
+    Ok(TransitiveReductionResult {
+        reduced,
+        removed_edges,
+    })
+}
+
+/// Computes, for every node, the set of nodes reachable via a path of
+/// length >= 1 (a node reaching itself indicates a cycle).
+fn reachability_closure(graph: &DiGraph) -> Vec<HashSet<usize>> {
+    let n = graph.len();
+    let mut reach = vec![HashSet::new(); n];
+    for (start, reachable) in reach.iter_mut().enumerate() {
+        let mut stack: Vec<usize> = graph.successors(start).collect();
+        let mut visited = HashSet::new();
+        while let Some(node) = stack.pop() {
+            if visited.insert(node) {
+                reachable.insert(node);
+                stack.extend(graph.successors(node));
+            }
+        }
+    }
+    reach
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_graph(edges: &[(usize, usize)]) -> DiGraph {
+        let mut g = DiGraph::new();
+        let max_node = edges.iter().flat_map(|(a, b)| [*a, *b]).max().unwrap_or(0);
+        for i in 0..=max_node {
+            g.add_node(&format!("n{}", i));
+        }
+        for (from, to) in edges {
+            g.add_edge(*from, *to);
+        }
+        g
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let g = DiGraph::new();
+        let result = transitive_reduction(&g).unwrap();
+        assert!(result.removed_edges.is_empty());
+    }
+
+    #[test]
+    fn test_redundant_shortcut_removed() {
+        // 0 -> 1 -> 2, plus a redundant 0 -> 2 shortcut.
+        let g = make_graph(&[(0, 1), (1, 2), (0, 2)]);
+        let result = transitive_reduction(&g).unwrap();
+        assert_eq!(result.removed_edges, vec![(0, 2)]);
+        assert_eq!(result.reduced.len(), 3);
+    }
+
+    #[test]
+    fn test_no_redundant_edges() {
+        // A diamond where neither cross edge is implied by the other.
+        let g = make_graph(&[(0, 1), (0, 2), (1, 3), (2, 3)]);
+        let result = transitive_reduction(&g).unwrap();
+        assert!(result.removed_edges.is_empty());
+    }
+
+    #[test]
+    fn test_chain_of_shortcuts() {
+        // 0 -> 1 -> 2 -> 3, with every longer shortcut also present.
+        let g = make_graph(&[(0, 1), (1, 2), (2, 3), (0, 2), (0, 3), (1, 3)]);
+        let result = transitive_reduction(&g).unwrap();
+        let mut removed = result.removed_edges.clone();
+        removed.sort_unstable();
+        assert_eq!(removed, vec![(0, 2), (0, 3), (1, 3)]);
+    }
+
+    #[test]
+    fn test_cyclic_graph_is_rejected() {
+        let g = make_graph(&[(0, 1), (1, 2), (2, 0)]);
+        assert_eq!(transitive_reduction(&g).unwrap_err(), TredError::Cyclic);
+    }
+}